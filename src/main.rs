@@ -1,5 +1,6 @@
 #[macro_use]
 extern crate serde;
+extern crate cargo_metadata;
 extern crate clap;
 extern crate structopt;
 extern crate subprocess;
@@ -8,6 +9,7 @@ extern crate toml;
 use std::collections::HashMap;
 use std::fs::File;
 use std::io::Read;
+use std::path::{Path, PathBuf};
 use std::process;
 use structopt::StructOpt;
 use subprocess::{Exec, ExitStatus};
@@ -17,13 +19,48 @@ use subprocess::{Exec, ExitStatus};
 enum Cli {
     #[structopt(name = "cmd")]
     Cmd {
-        #[structopt(name = "command", index = 1)]
-        command: String,
+        #[structopt(name = "command", index = 1, required_unless = "list")]
+        command: Option<String>,
         #[structopt(multiple = true)]
         rest: Vec<String>,
+
+        /// Restrict a workspace run to the named member(s). Implies
+        /// fanning out across the workspace even outside the root.
+        #[structopt(short = "p", long = "package", number_of_values = 1)]
+        package: Vec<String>,
+
+        /// Run the command in every workspace member that defines it,
+        /// regardless of the invocation directory.
+        #[structopt(long = "all")]
+        all: bool,
+
+        /// Print every command name defined in the manifest (including
+        /// resolved pre/post hooks) and exit without running anything.
+        #[structopt(long = "list")]
+        list: bool,
+
+        /// Suppress the `> <command>` echo and `[name]` headers.
+        #[structopt(short = "q", long = "quiet", conflicts_with = "verbose")]
+        quiet: bool,
+
+        /// Additionally print the resolved environment and working
+        /// directory for each command.
+        #[structopt(short = "v", long = "verbose", conflicts_with = "quiet")]
+        verbose: bool,
+
+        /// Path to the Cargo.toml to use. Defaults to the nearest
+        /// Cargo.toml found by walking up from the current directory.
+        #[structopt(long = "manifest-path", parse(from_os_str))]
+        manifest_path: Option<PathBuf>,
     },
 }
 
+/// Output-noise settings shared by every command invocation in a run.
+struct RunOptions {
+    quiet: bool,
+    verbose: bool,
+}
+
 #[derive(Deserialize, Debug)]
 struct Cargotoml {
     package: Option<WithMetadata>,
@@ -37,45 +74,232 @@ struct WithMetadata {
 
 #[derive(Deserialize, Debug)]
 struct Metadata {
-    commands: HashMap<String, String>,
+    commands: HashMap<String, CommandEntry>,
+}
+
+/// A single command entry in `[package.metadata.commands]`. Either the
+/// plain shell line shorthand, or a table giving the line plus the
+/// environment and working directory it should run with.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(untagged)]
+enum CommandEntry {
+    Bare(String),
+    Full {
+        run: String,
+        #[serde(default)]
+        env: HashMap<String, String>,
+        cwd: Option<String>,
+    },
+}
+
+impl CommandEntry {
+    fn run(&self) -> &str {
+        match self {
+            CommandEntry::Bare(run) => run,
+            CommandEntry::Full { run, .. } => run,
+        }
+    }
+
+    fn env(&self) -> HashMap<String, String> {
+        match self {
+            CommandEntry::Bare(_) => HashMap::new(),
+            CommandEntry::Full { env, .. } => env.clone(),
+        }
+    }
+
+    fn cwd(&self) -> Option<&str> {
+        match self {
+            CommandEntry::Bare(_) => None,
+            CommandEntry::Full { cwd, .. } => cwd.as_deref(),
+        }
+    }
+}
+
+/// A workspace member's resolved manifest: where it lives on disk and the
+/// commands it defines.
+struct Member {
+    name: String,
+    dir: PathBuf,
+    commands: HashMap<String, CommandEntry>,
 }
 
 fn main() {
     let cli = Cli::from_args();
-    let (command, rest) = match cli {
-        Cli::Cmd { command, rest } => (command, rest),
+    let (command, rest, package, all, list, quiet, verbose, manifest_path) = match cli {
+        Cli::Cmd {
+            command,
+            rest,
+            package,
+            all,
+            list,
+            quiet,
+            verbose,
+            manifest_path,
+        } => (command, rest, package, all, list, quiet, verbose, manifest_path),
     };
-    let commands = unwrap_or_exit(get_commands(&command));
+
+    if list {
+        list_commands(&package, all, &manifest_path);
+        return;
+    }
+
+    let command = unwrap_or_exit(command.ok_or_else(|| "The argument '<command>' was not provided".to_string()));
+    let opts = RunOptions { quiet, verbose };
+
+    if all || !package.is_empty() {
+        let members = unwrap_or_exit(get_workspace_members(&package, &manifest_path));
+        run_across_members(&command, &rest, &members, &opts);
+    } else if let Some(members) = unwrap_or_exit(workspace_members_if_root(&manifest_path)) {
+        run_across_members(&command, &rest, &members, &opts);
+    } else {
+        let manifest = unwrap_or_exit(find_manifest(&manifest_path));
+        let commands = unwrap_or_exit(get_commands(&command, &manifest));
+        run_commands(&rest, &commands, &opts, manifest_dir(&manifest));
+    }
+}
+
+/// Implements `--list`: prints every command name defined in the
+/// manifest(s) in scope, without running anything.
+fn list_commands(package: &[String], all: bool, manifest_path: &Option<PathBuf>) {
+    if all || !package.is_empty() {
+        let members = unwrap_or_exit(get_workspace_members(package, manifest_path));
+        for member in &members {
+            println!("\n[{}]", member.name);
+            print_command_names(&member.commands);
+        }
+    } else if let Some(members) = unwrap_or_exit(workspace_members_if_root(manifest_path)) {
+        for member in &members {
+            println!("\n[{}]", member.name);
+            print_command_names(&member.commands);
+        }
+    } else {
+        let manifest = unwrap_or_exit(find_manifest(manifest_path));
+        let cargo_commands = unwrap_or_exit(get_cargo_commands(&manifest));
+        print_command_names(&cargo_commands);
+    }
+}
+
+fn print_command_names(cargo_commands: &HashMap<String, CommandEntry>) {
+    let mut names: Vec<&String> = cargo_commands.keys().collect();
+    names.sort();
+    for name in names {
+        println!("{}", name);
+    }
+}
+
+/// Runs a resolved command list (pre/main/post) against a single crate's
+/// own Cargo.toml, executing relative to the manifest's directory rather
+/// than the shell's current directory.
+fn run_commands(
+    rest: &[String],
+    commands: &[(String, CommandEntry)],
+    opts: &RunOptions,
+    base_dir: &Path,
+) {
     let is_multiple_commands = commands.len() > 1;
 
-    for (index, command) in commands.iter().enumerate() {
-        if is_multiple_commands {
-            println!("\n[{}]", &command.0);
+    for (name, command) in commands {
+        if is_multiple_commands && !opts.quiet {
+            println!("\n[{}]", name);
         }
-        let command = &command.1;
-        let exit = execute_command(command, &rest);
+        execute_command_in(name, command, rest, base_dir, opts);
+    }
+}
 
-        if exit.success() {
-            if index == commands.len() {
-                process::exit(0);
-            }
-        } else {
-            match exit {
-                ExitStatus::Exited(exit_code) => process::exit(exit_code as i32),
-                _ => process::exit(1),
+/// Runs `command` (plus its pre/post hooks) in every member that defines
+/// it, printing a `[member-name]` header before each invocation.
+fn run_across_members(command: &str, rest: &[String], members: &[Member], opts: &RunOptions) {
+    let mut ran_any = false;
+
+    for member in members {
+        let commands = resolve_commands(command, &member.commands);
+        if commands.is_empty() {
+            continue;
+        }
+        ran_any = true;
+
+        if !opts.quiet {
+            println!("\n[{}]", member.name);
+        }
+        for (name, command_entry) in &commands {
+            if commands.len() > 1 && !opts.quiet {
+                println!("[{}]", name);
             }
+            execute_command_in(name, command_entry, rest, &member.dir, opts);
         }
     }
+
+    if !ran_any {
+        unwrap_or_exit(Err(format!(
+            "Command \"{}\" not found in any workspace member",
+            command
+        )) as Result<(), String>);
+    }
 }
 
-fn execute_command(command: &str, rest: &Vec<String>) -> ExitStatus {
+/// Runs a single resolved command, exiting the process immediately (with
+/// the failing command's own exit code where one exists) if it fails to
+/// launch or returns non-zero, rather than letting that failure pass for
+/// success or get lost in a generic error.
+fn execute_command_in(
+    name: &str,
+    command: &CommandEntry,
+    rest: &[String],
+    base_dir: &Path,
+    opts: &RunOptions,
+) {
     // This is naughty but Exec::shell doesn't let us do it with .args because
     // it ends up as an argument to sh/cmd.exe instead of our user command
     // or escaping things weirdly.
-    let command = format!("{} {}", command, rest.join(" "));
-    println!("> {}", command);
-    let sh = Exec::shell(command);
-    sh.join().unwrap_or(ExitStatus::Exited(0))
+    let command_line = format!("{} {}", command.run(), rest.join(" "));
+    if !opts.quiet {
+        println!("> {}", command_line);
+    }
+
+    let dir = match command.cwd() {
+        Some(cwd) => base_dir.join(cwd),
+        None => base_dir.to_path_buf(),
+    };
+    let env = command.env();
+
+    if opts.verbose {
+        println!("  cwd: {}", dir.display());
+        if !env.is_empty() {
+            println!("  env:");
+            let mut keys: Vec<&String> = env.keys().collect();
+            keys.sort();
+            for key in keys {
+                println!("    {}={}", key, env[key]);
+            }
+        }
+    }
+
+    let mut sh = Exec::shell(&command_line).cwd(dir);
+    for (key, value) in env {
+        sh = sh.env(key, value);
+    }
+
+    match sh.join() {
+        Ok(ExitStatus::Exited(0)) => {}
+        Ok(ExitStatus::Exited(code)) => {
+            eprintln!(
+                "Command \"{}\" ({}) exited with status {}",
+                name, command_line, code
+            );
+            process::exit(code as i32);
+        }
+        Ok(status) => {
+            eprintln!(
+                "Command \"{}\" ({}) exited with status {:?}",
+                name, command_line, status
+            );
+            process::exit(1);
+        }
+        Err(err) => {
+            eprintln!("Command \"{}\" failed to run: {}", name, err);
+            process::exit(1);
+        }
+    }
 }
 
 fn unwrap_or_exit<T>(result: Result<T, String>) -> T {
@@ -87,11 +311,8 @@ fn unwrap_or_exit<T>(result: Result<T, String>) -> T {
     }
 }
 
-fn get_commands(command: &str) -> Result<Vec<(String, String)>, String> {
-    let mut cargo_toml = File::open("Cargo.toml").or(Err(
-        "Could not find or open Cargo.toml in the current directory",
-    ))?;
-    let mut cargo_str = String::new();
+fn get_commands(command: &str, manifest: &Path) -> Result<Vec<(String, CommandEntry)>, String> {
+    let cargo_commands = get_cargo_commands(manifest)?;
     let mut commands = vec![];
     let names = vec![
         format!("pre{}", command),
@@ -99,11 +320,6 @@ fn get_commands(command: &str) -> Result<Vec<(String, String)>, String> {
         format!("post{}", command),
     ];
 
-    cargo_toml
-        .read_to_string(&mut cargo_str)
-        .or(Err("Could not read the contents of Cargo.toml"))?;
-
-    let cargo_commands = get_commands_from_str(&cargo_str)?;
     for name in names {
         let command_to_run = &cargo_commands.get(&name);
 
@@ -112,18 +328,95 @@ fn get_commands(command: &str) -> Result<Vec<(String, String)>, String> {
         }
 
         if command_to_run.is_some() {
-            commands.push((name, command_to_run.unwrap().to_string()));
+            commands.push((name, command_to_run.unwrap().clone()));
         }
     }
 
     Ok(commands)
 }
 
-fn get_commands_from_str(cargo_str: &str) -> Result<HashMap<String, String>, String> {
+/// Reads and parses `manifest` into its command map, with no pre/post
+/// resolution applied yet.
+fn get_cargo_commands(manifest: &Path) -> Result<HashMap<String, CommandEntry>, String> {
+    let mut cargo_toml = File::open(manifest)
+        .or(Err(format!("Could not open {}", manifest.display())))?;
+    let mut cargo_str = String::new();
+    cargo_toml
+        .read_to_string(&mut cargo_str)
+        .or(Err(format!("Could not read {}", manifest.display())))?;
+
+    get_commands_from_str(&cargo_str)
+}
+
+/// Returns the explicit `--manifest-path`, or the nearest `Cargo.toml`
+/// found by walking up from the current directory, the way cargo itself
+/// locates a manifest.
+fn find_manifest(manifest_path: &Option<PathBuf>) -> Result<PathBuf, String> {
+    if let Some(path) = manifest_path {
+        return Ok(path.clone());
+    }
+
+    let mut dir =
+        std::env::current_dir().or(Err("Could not determine the current directory"))?;
+
+    loop {
+        let candidate = dir.join("Cargo.toml");
+        if candidate.is_file() {
+            return Ok(candidate);
+        }
+
+        if !dir.pop() {
+            return Err(
+                "Could not find Cargo.toml in the current directory or any parent".to_string(),
+            );
+        }
+    }
+}
+
+/// The directory a manifest lives in, used as the base for command
+/// execution so commands run relative to the manifest rather than the
+/// shell's current directory.
+fn manifest_dir(manifest: &Path) -> &Path {
+    match manifest.parent() {
+        Some(p) if !p.as_os_str().is_empty() => p,
+        _ => Path::new("."),
+    }
+}
+
+/// Resolves `pre<command>`, `<command>` and `post<command>` against an
+/// already-loaded command map. Unlike `get_commands`, a missing
+/// `<command>` is not an error here: it just yields an empty `Vec` so
+/// callers fanning out across a workspace can skip members that don't
+/// define it.
+fn resolve_commands(
+    command: &str,
+    cargo_commands: &HashMap<String, CommandEntry>,
+) -> Vec<(String, CommandEntry)> {
+    if !cargo_commands.contains_key(command) {
+        return vec![];
+    }
+
+    let names = vec![
+        format!("pre{}", command),
+        command.to_string(),
+        format!("post{}", command),
+    ];
+
+    names
+        .into_iter()
+        .filter_map(|name| {
+            cargo_commands
+                .get(&name)
+                .map(|command_to_run| (name, command_to_run.clone()))
+        })
+        .collect()
+}
+
+fn get_commands_from_str(cargo_str: &str) -> Result<HashMap<String, CommandEntry>, String> {
     let cargo_toml: Cargotoml =
         toml::from_str(&cargo_str[..]).or(Err("Could not find commands in Cargo.toml"))?;
 
-    let mut cargo_commands: HashMap<String, String> = HashMap::new();
+    let mut cargo_commands: HashMap<String, CommandEntry> = HashMap::new();
 
     if let Some(package) = cargo_toml.package {
         cargo_commands.extend(package.metadata.commands);
@@ -136,6 +429,160 @@ fn get_commands_from_str(cargo_str: &str) -> Result<HashMap<String, String>, Str
     Ok(cargo_commands)
 }
 
+/// If the invocation is at a workspace root with more than one member,
+/// returns every member's resolved commands so the caller can fan out
+/// automatically. Returns `None` for a plain (non-workspace) crate, or
+/// for a member's own subdirectory inside a workspace, so the
+/// single-crate path is unaffected there.
+///
+/// `cargo_metadata` resolves the whole workspace's metadata regardless of
+/// which manifest inside it you point it at, so fan-out can't simply key
+/// off `workspace_members.len()` — that's true from inside any member's
+/// own directory too. We additionally require the resolved manifest's
+/// directory to match `metadata.workspace_root`.
+fn workspace_members_if_root(
+    manifest_path: &Option<PathBuf>,
+) -> Result<Option<Vec<Member>>, String> {
+    let metadata = run_cargo_metadata(manifest_path)?;
+
+    if metadata.workspace_members.len() <= 1 {
+        return Ok(None);
+    }
+
+    let manifest = find_manifest(manifest_path)?;
+    let invocation_dir = canonicalize_or_self(manifest_dir(&manifest));
+    let workspace_root = canonicalize_or_self(metadata.workspace_root.as_std_path());
+
+    if invocation_dir != workspace_root {
+        return Ok(None);
+    }
+
+    Ok(Some(load_members(&metadata, &[])?))
+}
+
+/// Canonicalizes `path`, falling back to the path itself (as an owned
+/// `PathBuf`) if it can't be resolved (e.g. it doesn't exist yet).
+fn canonicalize_or_self(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// Loads every workspace member (optionally filtered to `names`) via
+/// `cargo_metadata`, the way cargo-fmt enumerates members for `--all`.
+fn get_workspace_members(
+    names: &[String],
+    manifest_path: &Option<PathBuf>,
+) -> Result<Vec<Member>, String> {
+    let metadata = run_cargo_metadata(manifest_path)?;
+
+    load_members(&metadata, names)
+}
+
+fn run_cargo_metadata(
+    manifest_path: &Option<PathBuf>,
+) -> Result<cargo_metadata::Metadata, String> {
+    let mut cmd = cargo_metadata::MetadataCommand::new();
+    if let Some(path) = manifest_path {
+        cmd.manifest_path(path);
+    }
+
+    cmd.exec().or(Err("Could not run `cargo metadata`".to_string()))
+}
+
+fn load_members(metadata: &cargo_metadata::Metadata, names: &[String]) -> Result<Vec<Member>, String> {
+    let mut members = vec![];
+
+    for id in &metadata.workspace_members {
+        let package = metadata
+            .packages
+            .iter()
+            .find(|p| &p.id == id)
+            .ok_or("Malformed `cargo metadata` output: missing workspace member")?;
+
+        if !names.is_empty() && !names.contains(&package.name) {
+            continue;
+        }
+
+        // `Package::manifest_path` is `camino::Utf8PathBuf`, not
+        // `std::path::PathBuf` (since cargo_metadata 0.14) — it implements
+        // `Display` directly and converts to `&Path` via `as_std_path`.
+        let manifest_path = &package.manifest_path;
+        let mut cargo_toml =
+            File::open(manifest_path).or(Err(format!("Could not open {}", manifest_path)))?;
+        let mut cargo_str = String::new();
+        cargo_toml
+            .read_to_string(&mut cargo_str)
+            .or(Err(format!("Could not read {}", manifest_path)))?;
+
+        let commands = get_commands_from_str(&cargo_str).unwrap_or_default();
+        let dir = manifest_path
+            .parent()
+            .ok_or("Malformed manifest path")?
+            .as_std_path()
+            .to_path_buf();
+
+        members.push(Member {
+            name: package.name.clone(),
+            dir,
+            commands,
+        });
+    }
+
+    // A command defined only in the root `[workspace.metadata.commands]`
+    // table (rather than any member's own manifest) would otherwise be
+    // unreachable once we fan out per-member. Only do this for an
+    // unfiltered fan-out — a `-p`/`--package` restriction means the user
+    // wants just those members, not a workspace-level fallback.
+    if names.is_empty() {
+        let root_dir = metadata.workspace_root.as_std_path().to_path_buf();
+        members = with_root_fallback(members, root_dir, workspace_root_commands(metadata));
+    }
+
+    Ok(members)
+}
+
+/// Reads the workspace root manifest's own `[workspace.metadata.commands]`
+/// table. Returns an empty map (rather than erroring) if the manifest is
+/// missing or has no such table, since this is only ever used as a
+/// fallback.
+fn workspace_root_commands(metadata: &cargo_metadata::Metadata) -> HashMap<String, CommandEntry> {
+    #[derive(Deserialize)]
+    struct RootToml {
+        workspace: Option<WithMetadata>,
+    }
+
+    let manifest_path = metadata.workspace_root.join("Cargo.toml");
+
+    File::open(&manifest_path)
+        .ok()
+        .and_then(|mut cargo_toml| {
+            let mut cargo_str = String::new();
+            cargo_toml.read_to_string(&mut cargo_str).ok()?;
+            let root: RootToml = toml::from_str(&cargo_str).ok()?;
+            root.workspace.map(|workspace| workspace.metadata.commands)
+        })
+        .unwrap_or_default()
+}
+
+/// Appends a synthetic "workspace" member carrying the root-level
+/// commands, if there are any, so `resolve_commands` finds commands that
+/// no real member defines. The synthetic member is resolved like any
+/// other: when a real member also defines the requested command, both run.
+fn with_root_fallback(
+    mut members: Vec<Member>,
+    root_dir: PathBuf,
+    root_commands: HashMap<String, CommandEntry>,
+) -> Vec<Member> {
+    if !root_commands.is_empty() {
+        members.push(Member {
+            name: "workspace".to_string(),
+            dir: root_dir,
+            commands: root_commands,
+        });
+    }
+
+    members
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -152,7 +599,7 @@ mod tests {
 
         let commands = get_commands_from_str(cargo_str).unwrap();
         assert_eq!(commands.len(), 1);
-        assert_eq!(commands.get("test"), Some(&"echo 'test'".to_string()));
+        assert_eq!(commands.get("test").map(CommandEntry::run), Some("echo 'test'"));
     }
 
     #[test]
@@ -166,6 +613,89 @@ mod tests {
 
         let commands = get_commands_from_str(cargo_str).unwrap();
         assert_eq!(commands.len(), 1);
-        assert_eq!(commands.get("test"), Some(&"echo 'test from workspace'".to_string()));
+        assert_eq!(
+            commands.get("test").map(CommandEntry::run),
+            Some("echo 'test from workspace'")
+        );
+    }
+
+    #[test]
+    fn test_resolve_commands_skips_missing() {
+        let mut cargo_commands = HashMap::new();
+        cargo_commands.insert("build".to_string(), CommandEntry::Bare("cargo build".to_string()));
+
+        let commands = resolve_commands("build", &cargo_commands);
+        assert_eq!(commands.len(), 1);
+        assert_eq!(commands[0].0, "build");
+        assert_eq!(commands[0].1.run(), "cargo build");
+
+        let commands = resolve_commands("test", &cargo_commands);
+        assert!(commands.is_empty());
+    }
+
+    #[test]
+    fn test_get_commands_from_full_table_str() {
+        let cargo_str = r#"
+        [package]
+        name = "test"
+        version = "0.1.0"
+        [package.metadata.commands.test]
+        run = "echo 'test'"
+        cwd = "sub"
+        [package.metadata.commands.test.env]
+        RUST_LOG = "debug"
+        "#;
+
+        let commands = get_commands_from_str(cargo_str).unwrap();
+        let test = commands.get("test").unwrap();
+        assert_eq!(test.run(), "echo 'test'");
+        assert_eq!(test.cwd(), Some("sub"));
+        assert_eq!(test.env().get("RUST_LOG"), Some(&"debug".to_string()));
+    }
+
+    #[test]
+    fn test_find_manifest_prefers_explicit_path() {
+        let explicit = PathBuf::from("/some/other/Cargo.toml");
+        let found = find_manifest(&Some(explicit.clone())).unwrap();
+        assert_eq!(found, explicit);
+    }
+
+    #[test]
+    fn test_root_fallback_reaches_workspace_only_commands() {
+        let mut member_commands = HashMap::new();
+        member_commands.insert("build".to_string(), CommandEntry::Bare("cargo build".to_string()));
+        let members = vec![Member {
+            name: "crate-a".to_string(),
+            dir: PathBuf::from("/ws/crate-a"),
+            commands: member_commands,
+        }];
+
+        let mut root_commands = HashMap::new();
+        root_commands.insert("lint".to_string(), CommandEntry::Bare("cargo clippy".to_string()));
+        let members = with_root_fallback(members, PathBuf::from("/ws"), root_commands);
+
+        assert_eq!(members.len(), 2);
+        assert!(resolve_commands("build", &members[0].commands).len() == 1);
+        assert!(resolve_commands("lint", &members[0].commands).is_empty());
+        assert_eq!(members[1].name, "workspace");
+        assert_eq!(resolve_commands("lint", &members[1].commands).len(), 1);
+    }
+
+    #[test]
+    fn test_root_fallback_skipped_when_no_root_commands() {
+        let members = vec![Member {
+            name: "crate-a".to_string(),
+            dir: PathBuf::from("/ws/crate-a"),
+            commands: HashMap::new(),
+        }];
+
+        let members = with_root_fallback(members, PathBuf::from("/ws"), HashMap::new());
+        assert_eq!(members.len(), 1);
+    }
+
+    #[test]
+    fn test_manifest_dir_of_bare_relative_path_is_cwd() {
+        assert_eq!(manifest_dir(Path::new("Cargo.toml")), Path::new("."));
+        assert_eq!(manifest_dir(Path::new("sub/Cargo.toml")), Path::new("sub"));
     }
 }